@@ -0,0 +1,94 @@
+use cpp_data::{CppData, CppTypeAllocationPlace};
+use cpp_ffi_data::CppFfiHeaderData;
+use cpp_ffi_generator;
+use cpp_method::CppMethod;
+use cpp_type::CppType;
+use common::errors::Result;
+use std::collections::HashMap;
+
+/// Type of a user-supplied filter that can blacklist individual
+/// methods from FFI generation
+pub type CppFfiGeneratorFilterFn = Fn(&CppMethod) -> Result<bool>;
+
+/// Top-level settings for generating one FFI wrapper crate
+pub struct Config {
+  /// Name of the generated C++ wrapper library
+  cpp_ffi_lib_name: String,
+  /// User-supplied method blacklist/whitelist filters
+  cpp_ffi_generator_filters: Vec<Box<CppFfiGeneratorFilterFn>>,
+  /// See `set_generate_protected_shims`
+  generate_protected_shims: bool,
+  /// See `instantiate_template`
+  template_instantiations: HashMap<String, Vec<Vec<CppType>>>,
+  /// See `set_allocation_place`
+  allocation_place_overrides: HashMap<String, CppTypeAllocationPlace>,
+}
+
+impl Config {
+  /// Creates a new config for a crate that will wrap `cpp_ffi_lib_name`
+  pub fn new(cpp_ffi_lib_name: &str) -> Config {
+    Config {
+      cpp_ffi_lib_name: cpp_ffi_lib_name.to_string(),
+      cpp_ffi_generator_filters: Vec::new(),
+      generate_protected_shims: false,
+      template_instantiations: HashMap::new(),
+      allocation_place_overrides: HashMap::new(),
+    }
+  }
+
+  /// Adds a filter that can reject individual methods from FFI
+  /// generation
+  pub fn add_cpp_ffi_generator_filter(&mut self, filter: Box<CppFfiGeneratorFilterFn>) {
+    self.cpp_ffi_generator_filters.push(filter);
+  }
+
+  /// Enables generation of `<lib>_ProtectedShim_<Class>` subclasses
+  /// that expose each class's protected instance methods as public
+  /// forwarding members. Disabled by default, since it grows the
+  /// generated API surface of every class that has protected methods.
+  pub fn set_generate_protected_shims(&mut self, value: bool) {
+    self.generate_protected_shims = value;
+  }
+
+  /// Registers a concrete instantiation of a template method, so it can
+  /// be exposed through the FFI like a regular, fully-concrete method.
+  /// `class_or_method_path` is either `"Class::method"` to instantiate
+  /// one method, just `"Class"` to apply `types` to every template
+  /// method of that class, or the plain function name for a free
+  /// function template. Only methods with template parameters of their
+  /// own are instantiated; members of a class template that have none
+  /// of their own are not. Can be
+  /// called more than once for the same path to register several
+  /// instantiations (e.g. both `findChild<QWidget>` and
+  /// `findChild<QLabel>`).
+  pub fn instantiate_template(&mut self, class_or_method_path: &str, types: &[CppType]) {
+    self.template_instantiations
+      .entry(class_or_method_path.to_string())
+      .or_insert_with(Vec::new)
+      .push(types.to_vec());
+  }
+
+  /// Overrides the allocation place used when generating the FFI
+  /// signatures of the methods that `class_name` itself declares
+  /// (including the field accessors and template instantiations
+  /// generated for it). Methods of other classes and free functions
+  /// that return `class_name` by value are not affected and keep the
+  /// default allocation place. Small value-like
+  /// classes (points, sizes, colors) are good candidates for `Stack`;
+  /// large or polymorphic types should stay on `Heap`.
+  pub fn set_allocation_place(&mut self, class_name: &str, place: CppTypeAllocationPlace) {
+    self.allocation_place_overrides
+      .insert(class_name.to_string(), place);
+  }
+
+  /// Runs the FFI generation stage using this configuration
+  pub fn run_cpp_ffi_generator(&self, cpp_data: &CppData) -> Result<Vec<CppFfiHeaderData>> {
+    let filters = self.cpp_ffi_generator_filters.iter().collect();
+    cpp_ffi_generator::run(cpp_data,
+                           self.cpp_ffi_lib_name.clone(),
+                           filters,
+                           self.generate_protected_shims,
+                           &self.template_instantiations,
+                           &self.allocation_place_overrides)
+  }
+}
@@ -0,0 +1,105 @@
+use cpp_ffi_data::{CppFfiHeaderData, CppFfiProtectedShim, CppFfiOverrider};
+use cpp_type::CppTypeBase;
+use common::errors::Result;
+
+/// Renders the C++ declarations of the shim and overrider subclasses
+/// that `CppFfiGenerator::generate_protected_shims` and
+/// `CppFfiGenerator::generate_override_wrappers` describe in
+/// `header.protected_shims`/`header.overriders`. This is what actually
+/// turns that metadata into the extra C++ source the two subsystems
+/// depend on; without it the generated shim/overrider FFI methods
+/// would reference classes that are never declared.
+pub fn generate_shim_and_overrider_declarations(header: &CppFfiHeaderData) -> Result<String> {
+  let mut code = String::new();
+  for shim in &header.protected_shims {
+    code.push_str(&generate_protected_shim_declaration(shim));
+  }
+  for overrider in &header.overriders {
+    code.push_str(&generate_overrider_declaration(overrider)?);
+  }
+  Ok(code)
+}
+
+/// A protected shim only needs to re-expose already-implemented
+/// methods under public visibility, so a `using`-declaration per
+/// method is enough; it also preserves overload sets automatically.
+fn generate_protected_shim_declaration(shim: &CppFfiProtectedShim) -> String {
+  let mut code = format!("class {} : public {} {{\npublic:\n",
+                         shim.shim_class_name,
+                         shim.base_class_name);
+  for method_name in &shim.forwarded_method_names {
+    code.push_str(&format!("  using {}::{};\n", shim.base_class_name, method_name));
+  }
+  code.push_str("};\n\n");
+  code
+}
+
+/// An overrider needs one settable function pointer per virtual it can
+/// implement, plus the constructor/destructor pair. Each virtual is
+/// actually overridden: the body calls the stored callback if one has
+/// been set through `set_<method>`, and otherwise falls back to the
+/// base class implementation (or, for a pure virtual with no base
+/// implementation to fall back to, aborts through
+/// `cpp_to_rust_pure_virtual_called`). The function pointer and its
+/// `void* data` are kept as private members next to the methods that
+/// use them.
+fn generate_overrider_declaration(overrider: &CppFfiOverrider) -> Result<String> {
+  let mut code = format!("class {name} : public {base} {{\npublic:\n  {name}();\n  \
+                          virtual ~{name}();\n",
+                         name = overrider.overrider_class_name,
+                         base = overrider.base_class_name);
+  let mut storage = String::new();
+  for method in &overrider.overrides {
+    let function_pointer_type = method.function_type.to_cpp_code(None)?;
+    let return_type = method.return_type.to_cpp_code(None)?;
+    let args = method
+      .arguments
+      .iter()
+      .map(|arg| arg.argument_type.to_cpp_code(Some(&arg.name)))
+      .collect::<Result<Vec<_>>>()?
+      .join(", ");
+    let arg_names = method
+      .arguments
+      .iter()
+      .map(|arg| arg.name.clone())
+      .collect::<Vec<_>>();
+    let is_void = match method.return_type.base {
+      CppTypeBase::Void => true,
+      _ => false,
+    };
+    let mut forwarded_args = vec![format!("{}_data", method.name)];
+    forwarded_args.extend(arg_names.iter().cloned());
+    let call_callback = format!("{ret}{name}_func({args})",
+                                ret = if is_void { "" } else { "return " },
+                                name = method.name,
+                                args = forwarded_args.join(", "));
+    let fallback = if method.is_pure_virtual {
+      format!("cpp_to_rust_pure_virtual_called(\"{}::{}\")",
+             overrider.base_class_name,
+             method.name)
+    } else {
+      format!("{ret}{base}::{name}({args})",
+             ret = if is_void { "" } else { "return " },
+             base = overrider.base_class_name,
+             name = method.name,
+             args = arg_names.join(", "))
+    };
+    code.push_str(&format!("  void set_{name}({ty} func, void* data);\n", name = method.name, ty = function_pointer_type));
+    code.push_str(&format!("  virtual {ret} {name}({args}){cnst} override {{\n    \
+                            if ({name}_func) {{\n      {call_callback};\n    }}\n    \
+                            {fallback};\n  }}\n",
+                           ret = return_type,
+                           name = method.name,
+                           args = args,
+                           cnst = if method.is_const { " const" } else { "" },
+                           call_callback = call_callback,
+                           fallback = fallback));
+    storage.push_str(&format!("  {ty} {name}_func;\n  void* {name}_data;\n",
+                              ty = function_pointer_type,
+                              name = method.name));
+  }
+  code.push_str("private:\n");
+  code.push_str(&storage);
+  code.push_str("};\n\n");
+  Ok(code)
+}
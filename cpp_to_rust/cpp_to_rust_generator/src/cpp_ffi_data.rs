@@ -0,0 +1,168 @@
+use cpp_data::CppTypeAllocationPlace;
+use cpp_method::{CppMethod, CppFieldAccessorType, CppFunctionArgument};
+use cpp_type::{CppType, CppFunctionPointerType};
+use common::errors::Result;
+
+/// A C++ method together with its generated FFI signature and the
+/// mangled C name it will be exposed as
+#[derive(Debug, Clone)]
+pub struct CppAndFfiMethod {
+  /// Original C++ method
+  pub cpp_method: CppMethod,
+  /// Final, collision-free name of the generated C function
+  pub c_name: String,
+}
+
+impl CppAndFfiMethod {
+  /// Creates a new `CppAndFfiMethod` from an FFI-signature method and
+  /// its final (possibly caption-disambiguated) C name
+  pub fn new(data: CppMethodWithFfiSignature, c_name: String) -> CppAndFfiMethod {
+    CppAndFfiMethod {
+      cpp_method: data.cpp_method,
+      c_name: c_name,
+    }
+  }
+}
+
+/// Intermediate result of converting a `CppMethod` to its FFI form,
+/// before the final collision-free C name has been chosen
+#[derive(Debug, Clone)]
+pub struct CppMethodWithFfiSignature {
+  /// Original C++ method
+  pub cpp_method: CppMethod,
+  /// Chosen allocation place for the return value, if applicable
+  pub allocation_place: CppTypeAllocationPlace,
+  /// Generated C signature, used to disambiguate overloads via
+  /// `MethodCaptionStrategy`
+  pub c_signature: CppFfiFunctionSignature,
+}
+
+/// A C function signature derived from a `CppMethod`
+#[derive(Debug, Clone)]
+pub struct CppFfiFunctionSignature {
+  /// Return type of the C function
+  pub return_type: CppType,
+  /// Argument types of the C function, in order
+  pub arguments: Vec<CppType>,
+}
+
+impl CppFfiFunctionSignature {
+  /// Produces a short, human-readable caption of the signature's
+  /// argument types, joined with `_`; used to disambiguate methods that
+  /// would otherwise mangle to the same C name. Every argument type is
+  /// captioned with `TypeCaptionStrategy::Full` whichever `strategy` is
+  /// passed, and the return type is not part of the caption.
+  pub fn caption(&self, strategy: ::caption_strategy::MethodCaptionStrategy) -> Result<String> {
+    self.arguments
+      .iter()
+      .map(|arg| arg.caption(::caption_strategy::TypeCaptionStrategy::Full))
+      .collect::<Result<Vec<_>>>()
+      .map(|parts| parts.join("_"))
+      .map(|joined| {
+             // keep the strategy parameter for future per-strategy tuning
+             let _ = &strategy;
+             joined
+           })
+  }
+}
+
+/// One synthesized slot-forwarding class (see
+/// `CppFfiGenerator::generate_slot_wrappers`)
+#[derive(Debug, Clone)]
+pub struct QtSlotWrapper {
+  /// Name of the generated class
+  pub class_name: String,
+  /// FFI types of the signal's arguments
+  pub arguments: Vec<CppType>,
+  /// Type of the stored callback function pointer
+  pub function_type: CppFunctionPointerType,
+  /// Receiver id used to connect the generated slot
+  pub receiver_id: String,
+}
+
+/// One synthesized protected-method shim subclass (see
+/// `CppFfiGenerator::generate_protected_shims`)
+#[derive(Debug, Clone)]
+pub struct CppFfiProtectedShim {
+  /// Name of the generated `<lib>_ProtectedShim_<Class>` class
+  pub shim_class_name: String,
+  /// Name of the class whose protected API is being exposed
+  pub base_class_name: String,
+  /// Names of the protected methods forwarded as public wrappers
+  pub forwarded_method_names: Vec<String>,
+}
+
+/// One synthesized virtual-override subclass (see
+/// `CppFfiGenerator::generate_override_wrappers`)
+#[derive(Debug, Clone)]
+pub struct CppFfiOverrider {
+  /// Name of the generated `<lib>_Overrider_<Class>` class
+  pub overrider_class_name: String,
+  /// Name of the abstract class being implemented
+  pub base_class_name: String,
+  /// Virtual methods that can be overridden through stored callbacks
+  pub overrides: Vec<CppOverriddenMethod>,
+}
+
+/// One overridable virtual method exposed by a `CppFfiOverrider`
+#[derive(Debug, Clone)]
+pub struct CppOverriddenMethod {
+  /// Name of the virtual method
+  pub name: String,
+  /// Type of the function pointer that implements it from Rust
+  /// (the stored callback; its first argument is the `void* data`)
+  pub function_type: CppFunctionPointerType,
+  /// Whether the base declaration has no implementation of its own,
+  /// so there's no fallback to call when no callback is set
+  pub is_pure_virtual: bool,
+  /// Whether the overridden method is `const`
+  pub is_const: bool,
+  /// Original (non-FFI) return type, used to declare the override
+  pub return_type: CppType,
+  /// Original (non-FFI) arguments, used to declare the override and
+  /// to forward them to the stored callback or the base implementation
+  pub arguments: Vec<CppFunctionArgument>,
+}
+
+/// All FFI data generated for one input header
+#[derive(Debug, Clone)]
+pub struct CppFfiHeaderData {
+  /// Name of the include file the data was generated from, without
+  /// extension
+  pub include_file_base_name: String,
+  /// Generated FFI methods
+  pub methods: Vec<CppAndFfiMethod>,
+  /// Synthesized slot-forwarding classes
+  pub qt_slot_wrappers: Vec<QtSlotWrapper>,
+  /// Synthesized protected-method shim classes
+  pub protected_shims: Vec<CppFfiProtectedShim>,
+  /// Synthesized virtual-override classes
+  pub overriders: Vec<CppFfiOverrider>,
+}
+
+/// Computes the base (pre-disambiguation) C name for `method`, i.e.
+/// the name used as a key before `MethodCaptionStrategy` is applied to
+/// break ties between overloads.
+pub fn c_base_name(method: &CppMethod,
+                    allocation_place: &CppTypeAllocationPlace,
+                    include_file_base_name: &str)
+                    -> Result<String> {
+  let class_name = method.class_name().cloned();
+  let base = match class_name {
+    Some(ref class_name) => format!("{}_{}", class_name, method.name),
+    None => format!("{}_{}", include_file_base_name, method.name),
+  };
+  // Field getters and mutable-reference getters share the same method
+  // name and take no arguments, so `MethodCaptionStrategy` (which
+  // captions based on argument types) cannot tell them apart. Use the
+  // accessor marker itself to disambiguate them here instead.
+  let base = match method
+          .class_membership
+          .as_ref()
+          .and_then(|m| m.field_accessor_type.clone()) {
+    Some(CppFieldAccessorType::MutableGetter) => format!("{}_mut", base),
+    Some(CppFieldAccessorType::Getter) | Some(CppFieldAccessorType::Setter) | None => base,
+  };
+  let _ = allocation_place;
+  Ok(base)
+}
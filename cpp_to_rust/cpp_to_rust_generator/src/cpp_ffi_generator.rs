@@ -1,9 +1,11 @@
 use caption_strategy::{TypeCaptionStrategy, MethodCaptionStrategy};
-use cpp_data::{CppData, CppVisibility, create_cast_method, CppTypeAllocationPlace};
+use cpp_data::{CppData, CppVisibility, create_cast_method, CppTypeAllocationPlace, CppClassField};
 use cpp_type::{CppTypeRole, CppType, CppTypeBase, CppTypeIndirection, CppTypeClassBase,
                CppFunctionPointerType};
-use cpp_ffi_data::{CppAndFfiMethod, c_base_name, CppFfiHeaderData, QtSlotWrapper};
-use cpp_method::{CppMethod, CppMethodKind, CppFunctionArgument, CppMethodClassMembership};
+use cpp_ffi_data::{CppAndFfiMethod, c_base_name, CppFfiHeaderData, QtSlotWrapper,
+                   CppFfiProtectedShim, CppFfiOverrider, CppOverriddenMethod};
+use cpp_method::{CppMethod, CppMethodKind, CppFunctionArgument, CppMethodClassMembership,
+                 CppFieldAccessorType};
 use common::errors::{Result, ChainErr, unexpected};
 use common::log;
 use common::utils::{MapIfOk, add_to_multihash};
@@ -19,17 +21,32 @@ struct CppFfiGenerator<'a> {
   cpp_ffi_lib_name: String,
   /// FFI filters passed to `Config`
   filters: Vec<&'a Box<CppFfiGeneratorFilterFn>>,
+  /// Whether protected methods should be exposed through generated
+  /// shim subclasses (`Config::set_generate_protected_shims`)
+  generate_protected_shims: bool,
+  /// Explicit template instantiations registered through
+  /// `Config::instantiate_template`, keyed by class or method path
+  template_instantiations: &'a HashMap<String, Vec<Vec<CppType>>>,
+  /// Per-class return-value allocation place overrides registered
+  /// through `Config::set_allocation_place`
+  allocation_place_overrides: &'a HashMap<String, CppTypeAllocationPlace>,
 }
 
 /// Runs the FFI generator
 pub fn run(cpp_data: &CppData,
            cpp_ffi_lib_name: String,
-           filters: Vec<&Box<CppFfiGeneratorFilterFn>>)
+           filters: Vec<&Box<CppFfiGeneratorFilterFn>>,
+           generate_protected_shims: bool,
+           template_instantiations: &HashMap<String, Vec<Vec<CppType>>>,
+           allocation_place_overrides: &HashMap<String, CppTypeAllocationPlace>)
            -> Result<Vec<CppFfiHeaderData>> {
   let generator = CppFfiGenerator {
     cpp_data: cpp_data,
     cpp_ffi_lib_name: cpp_ffi_lib_name,
     filters: filters,
+    generate_protected_shims: generate_protected_shims,
+    template_instantiations: template_instantiations,
+    allocation_place_overrides: allocation_place_overrides,
   };
 
   let mut c_headers = Vec::new();
@@ -40,20 +57,53 @@ pub fn run(cpp_data: &CppData,
     .collect();
   include_name_list.sort();
 
+  let instantiated_template_methods = generator.instantiated_template_methods()?;
+
   for include_file in &include_name_list {
     let mut include_file_base_name = include_file.clone();
 
     if let Some(index) = include_file_base_name.find('.') {
       include_file_base_name = include_file_base_name[0..index].to_string();
     }
-    let methods = generator
-      .process_methods(&include_file_base_name,
-                       None,
-                       generator
-                         .cpp_data
-                         .methods
-                         .iter()
-                         .filter(|x| &x.include_file == include_file))?;
+    let field_accessor_methods: Vec<_> = generator
+      .cpp_data
+      .fields
+      .iter()
+      .filter(|x| &x.include_file == include_file)
+      .flat_map(|field| generator.field_accessor_methods(field))
+      .collect();
+    let instantiated_methods: Vec<_> = instantiated_template_methods
+      .iter()
+      .filter(|x| &x.include_file == include_file)
+      .collect();
+    let mut methods_by_class: HashMap<String, Vec<&CppMethod>> = HashMap::new();
+    for method in generator
+          .cpp_data
+          .methods
+          .iter()
+          .filter(|x| &x.include_file == include_file)
+          .chain(field_accessor_methods.iter())
+          .chain(instantiated_methods.into_iter()) {
+      let class_name = method.class_name().cloned().unwrap_or_default();
+      methods_by_class
+        .entry(class_name)
+        .or_insert_with(Vec::new)
+        .push(method);
+    }
+    let mut class_names: Vec<_> = methods_by_class.keys().cloned().collect();
+    class_names.sort();
+    let mut methods = Vec::new();
+    for class_name in class_names {
+      let allocation_place_override = generator
+        .allocation_place_overrides
+        .get(&class_name)
+        .cloned();
+      methods.extend(generator
+                        .process_methods(&include_file_base_name,
+                                         allocation_place_override,
+                                         methods_by_class[&class_name].iter().cloned())?);
+    }
+    methods.sort_by(|a, b| a.c_name.cmp(&b.c_name));
     if methods.is_empty() {
       log::llog(log::DebugFfiSkips,
                 || format!("Skipping empty include file {}", include_file));
@@ -62,12 +112,20 @@ pub fn run(cpp_data: &CppData,
                        include_file_base_name: include_file_base_name,
                        methods: methods,
                        qt_slot_wrappers: Vec::new(),
+                       protected_shims: Vec::new(),
+                       overriders: Vec::new(),
                      });
     }
   }
   if let Some(header) = generator.generate_slot_wrappers()? {
     c_headers.push(header);
   }
+  if let Some(header) = generator.generate_protected_shims()? {
+    c_headers.push(header);
+  }
+  if let Some(header) = generator.generate_override_wrappers()? {
+    c_headers.push(header);
+  }
   if c_headers.is_empty() {
     return Err("No FFI headers generated".into());
   }
@@ -128,6 +186,173 @@ impl<'a> CppFfiGenerator<'a> {
     Ok(true)
   }
 
+  /// Produces concrete `CppMethod`s for every template method that has
+  /// a matching registration in `Config::instantiate_template`. The
+  /// path used for lookup is `Class::method` for a class member, or the
+  /// plain method name otherwise; registering the class path alone
+  /// applies the instantiation to all of the class's template methods.
+  /// Only methods with `template_arguments` of their own are considered;
+  /// members of a class template without them are not instantiated.
+  fn instantiated_template_methods(&self) -> Result<Vec<CppMethod>> {
+    let mut result = Vec::new();
+    for method in &self.cpp_data.methods {
+      if method.template_arguments.is_none() {
+        continue;
+      }
+      let class_name = method.class_name().cloned();
+      let method_path = match class_name {
+        Some(ref class_name) => format!("{}::{}", class_name, method.name),
+        None => method.name.clone(),
+      };
+      let mut requested_types = Vec::new();
+      if let Some(instantiations) = self.template_instantiations.get(&method_path) {
+        requested_types.extend(instantiations.iter().cloned());
+      }
+      if let Some(ref class_name) = class_name {
+        if let Some(instantiations) = self.template_instantiations.get(class_name) {
+          requested_types.extend(instantiations.iter().cloned());
+        }
+      }
+      for concrete_types in requested_types {
+        match method.instantiate(&concrete_types) {
+          Ok(mut instantiated_method) => {
+            // The instantiated method is now fully concrete: clear the
+            // template markers so `should_process_method` treats it
+            // like any other regular method instead of rejecting it
+            // as an unresolved template.
+            instantiated_method.template_arguments = None;
+            instantiated_method.template_arguments_values = None;
+            // Two instantiations of the same template (e.g.
+            // `findChild<QWidget>` vs `findChild<QLabel>`) can differ
+            // only in their return type, which `MethodCaptionStrategy`
+            // cannot see since it captions argument types. Bake the
+            // requested types into the method name so each
+            // instantiation mangles to a distinct C name.
+            let type_captions = concrete_types
+              .iter()
+              .map(|t| t.caption(TypeCaptionStrategy::Full))
+              .collect::<Result<Vec<_>>>()?;
+            if !type_captions.is_empty() {
+              instantiated_method.name = format!("{}_{}",
+                                                  instantiated_method.name,
+                                                  type_captions.join("_"));
+            }
+            result.push(instantiated_method);
+          }
+          Err(msg) => {
+            log::llog(log::DebugFfiSkips, || {
+              format!("Unable to instantiate template method:\n{}\nError:{}\n",
+                      method.short_text(),
+                      msg)
+            });
+          }
+        }
+      }
+    }
+    Ok(result)
+  }
+
+  /// Synthesizes getter/setter `CppMethod`s for one public, non-static
+  /// data member so that it can be passed through `process_methods` like
+  /// any other method. Private, protected and static fields are not
+  /// exposed this way and produce no methods.
+  fn field_accessor_methods(&self, field: &CppClassField) -> Vec<CppMethod> {
+    if field.visibility != CppVisibility::Public || field.is_static {
+      return Vec::new();
+    }
+    let class_type = CppTypeClassBase {
+      name: field.class_name.clone(),
+      template_arguments: None,
+    };
+    // Only a by-value class field needs its getters turned into
+    // references; a field that is itself a pointer (e.g. `QWidget* w`)
+    // already has an indirection of its own, and turning that into a
+    // reference would silently drop the `*` and make the accessor
+    // bodies invalid.
+    let is_class_type = match (&field.field_type.base, &field.field_type.indirection) {
+      (&CppTypeBase::Class(..), &CppTypeIndirection::None) => true,
+      _ => false,
+    };
+    let const_getter_return_type = if is_class_type {
+      CppType {
+        base: field.field_type.base.clone(),
+        indirection: CppTypeIndirection::Ref,
+        is_const: true,
+        is_const2: field.field_type.is_const2,
+      }
+    } else {
+      field.field_type.clone()
+    };
+    let mutable_getter_return_type = CppType {
+      base: field.field_type.base.clone(),
+      indirection: if is_class_type {
+        CppTypeIndirection::Ref
+      } else {
+        CppTypeIndirection::Ptr
+      },
+      is_const: false,
+      is_const2: field.field_type.is_const2,
+    };
+    let create_accessor = |name: String,
+                           accessor_type: CppFieldAccessorType,
+                           return_type: CppType,
+                           arguments: Vec<CppFunctionArgument>,
+                           is_const: bool|
+     -> CppMethod {
+      CppMethod {
+        name: name,
+        class_membership: Some(CppMethodClassMembership {
+                                 class_type: class_type.clone(),
+                                 is_virtual: false,
+                                 is_pure_virtual: false,
+                                 is_const: is_const,
+                                 is_static: false,
+                                 visibility: CppVisibility::Public,
+                                 is_signal: false,
+                                 is_slot: false,
+                                 kind: CppMethodKind::Regular,
+                                 fake: None,
+                                 field_accessor_type: Some(accessor_type),
+                               }),
+        operator: None,
+        return_type: return_type,
+        arguments: arguments,
+        arguments_before_omitting: None,
+        allows_variadic_arguments: false,
+        include_file: field.include_file.clone(),
+        origin_location: None,
+        template_arguments: None,
+        template_arguments_values: None,
+        declaration_code: None,
+        doc: None,
+        inheritance_chain: Vec::new(),
+        is_ffi_whitelisted: false,
+        is_unsafe_static_cast: false,
+        is_direct_static_cast: false,
+        is_fake_inherited_method: false,
+      }
+    };
+    vec![create_accessor(field.name.clone(),
+                         CppFieldAccessorType::Getter,
+                         const_getter_return_type,
+                         vec![],
+                         true),
+         create_accessor(field.name.clone(),
+                         CppFieldAccessorType::MutableGetter,
+                         mutable_getter_return_type,
+                         vec![],
+                         false),
+         create_accessor(format!("set_{}", field.name),
+                         CppFieldAccessorType::Setter,
+                         CppType::void(),
+                         vec![CppFunctionArgument {
+                                name: "value".to_string(),
+                                argument_type: field.field_type.clone(),
+                                has_default_value: false,
+                              }],
+                         false)]
+  }
+
   /// Generates FFI wrappers for all specified methods,
   /// resolving all name conflicts using additional method captions.
   fn process_methods<'b, I>(&self,
@@ -275,6 +500,7 @@ impl<'a> CppFfiGenerator<'a> {
                                    is_slot: is_slot,
                                    kind: kind,
                                    fake: None,
+                                   field_accessor_type: None,
                                  }),
           operator: None,
           return_type: CppType::void(),
@@ -376,6 +602,340 @@ impl<'a> CppFfiGenerator<'a> {
                                  Some(CppTypeAllocationPlace::Heap),
                                  methods.iter())?,
               qt_slot_wrappers: qt_slot_wrappers,
+              protected_shims: Vec::new(),
+              overriders: Vec::new(),
+            }))
+  }
+
+  /// Groups all protected, non-static instance methods by the class
+  /// that declares them. Used to decide which classes need a shim
+  /// subclass to expose their protected API.
+  fn protected_methods_by_class(&self) -> HashMap<String, Vec<&CppMethod>> {
+    let mut result: HashMap<String, Vec<&CppMethod>> = HashMap::new();
+    for method in &self.cpp_data.methods {
+      if let Some(ref membership) = method.class_membership {
+        // Pure virtuals have no base implementation for the forwarding
+        // wrapper to call, and constructors/destructors aren't regular
+        // callable members, so none of them can become a forwarding
+        // wrapper here.
+        if membership.visibility == CppVisibility::Protected && !membership.is_static &&
+           !membership.is_signal && !membership.is_pure_virtual &&
+           membership.kind == CppMethodKind::Regular {
+          add_to_multihash(&mut result, membership.class_type.name.clone(), method);
+        }
+      }
+    }
+    result
+  }
+
+  /// Generates, for every class that has protected instance methods, a
+  /// `<lib>_ProtectedShim_<Class>` subclass that re-declares each
+  /// protected method as a public forwarding wrapper calling the base
+  /// implementation. This mirrors the synthetic-class-plus-FFI-methods
+  /// pattern already used by `generate_slot_wrappers`. The wrappers
+  /// then go through `process_methods` like any other public method.
+  fn generate_protected_shims(&'a self) -> Result<Option<CppFfiHeaderData>> {
+    let include_file_name = "protected_shims";
+    if !self.generate_protected_shims {
+      return Ok(None);
+    }
+    let by_class = self.protected_methods_by_class();
+    if by_class.is_empty() {
+      return Ok(None);
+    }
+    let mut methods = Vec::new();
+    let mut protected_shims = Vec::new();
+    let mut class_names: Vec<_> = by_class.keys().cloned().collect();
+    class_names.sort();
+    for class_name in class_names {
+      let shim_class_name = format!("{}_ProtectedShim_{}", self.cpp_ffi_lib_name, class_name);
+      let mut forwarded_method_names = Vec::new();
+      for method in &by_class[&class_name] {
+        let mut wrapper = (*method).clone();
+        wrapper.class_membership = Some(CppMethodClassMembership {
+                                           class_type: CppTypeClassBase {
+                                             name: shim_class_name.clone(),
+                                             template_arguments: None,
+                                           },
+                                           is_virtual: method
+                                             .class_membership
+                                             .as_ref()
+                                             .map_or(false, |m| m.is_virtual),
+                                           is_pure_virtual: false,
+                                           is_const: method
+                                             .class_membership
+                                             .as_ref()
+                                             .map_or(false, |m| m.is_const),
+                                           is_static: false,
+                                           visibility: CppVisibility::Public,
+                                           is_signal: false,
+                                           is_slot: false,
+                                           kind: CppMethodKind::Regular,
+                                           fake: None,
+                                           field_accessor_type: None,
+                                         });
+        wrapper.include_file = include_file_name.to_string();
+        // A single `using Base::method;` already imports the whole
+        // overload set, so overloaded protected methods must not add
+        // more than one forwarding declaration each.
+        if !forwarded_method_names.contains(&wrapper.name) {
+          forwarded_method_names.push(wrapper.name.clone());
+        }
+        methods.push(wrapper);
+      }
+      let cast_from = CppType {
+        base: CppTypeBase::Class(CppTypeClassBase {
+                                   name: shim_class_name.clone(),
+                                   template_arguments: None,
+                                 }),
+        indirection: CppTypeIndirection::Ptr,
+        is_const: false,
+        is_const2: false,
+      };
+      let cast_to = CppType {
+        base: CppTypeBase::Class(CppTypeClassBase {
+                                   name: class_name.clone(),
+                                   template_arguments: None,
+                                 }),
+        indirection: CppTypeIndirection::Ptr,
+        is_const: false,
+        is_const2: false,
+      };
+      methods.push(create_cast_method("static_cast",
+                                      &cast_from,
+                                      &cast_to,
+                                      false,
+                                      true,
+                                      include_file_name));
+      protected_shims.push(CppFfiProtectedShim {
+                              shim_class_name: shim_class_name,
+                              base_class_name: class_name,
+                              forwarded_method_names: forwarded_method_names,
+                            });
+    }
+    Ok(Some(CppFfiHeaderData {
+              include_file_base_name: include_file_name.to_string(),
+              methods: self.process_methods(include_file_name, None, methods.iter())?,
+              qt_slot_wrappers: Vec::new(),
+              protected_shims: protected_shims,
+              overriders: Vec::new(),
+            }))
+  }
+
+  /// Groups all virtual (non-static, non-signal) instance methods by
+  /// the class that declares them. Includes both pure and non-pure
+  /// virtuals, since an overrider must be able to stand in for either.
+  fn virtual_methods_by_class(&self) -> HashMap<String, Vec<&CppMethod>> {
+    let mut result: HashMap<String, Vec<&CppMethod>> = HashMap::new();
+    for method in &self.cpp_data.methods {
+      if let Some(ref membership) = method.class_membership {
+        if membership.is_virtual && !membership.is_static && !membership.is_signal {
+          add_to_multihash(&mut result, membership.class_type.name.clone(), method);
+        }
+      }
+    }
+    result
+  }
+
+  /// Collects the virtual methods that `class_name` can override,
+  /// including ones declared on base classes rather than directly on
+  /// `class_name` itself. An abstract class commonly leaves some of
+  /// its pure virtuals unimplemented and inherits them as-is, so
+  /// looking only at directly-declared virtuals would miss those and
+  /// leave the generated overrider class abstract. Methods are kept by
+  /// name only: where a method name is declared more than once along
+  /// the inheritance chain, the most derived declaration wins, and of
+  /// several overloads of one name only the first one found is
+  /// collected.
+  fn all_overridable_virtual_methods<'b>(&self,
+                                         virtual_methods: &HashMap<String, Vec<&'b CppMethod>>,
+                                         class_name: &str)
+                                         -> Vec<&'b CppMethod> {
+    let mut result = Vec::new();
+    let mut seen_method_names = HashSet::new();
+    let mut seen_classes = HashSet::new();
+    let mut classes_to_visit = vec![class_name.to_string()];
+    while let Some(current_class) = classes_to_visit.pop() {
+      if !seen_classes.insert(current_class.clone()) {
+        continue;
+      }
+      if let Some(methods) = virtual_methods.get(&current_class) {
+        for method in methods {
+          if seen_method_names.insert(method.name.clone()) {
+            result.push(*method);
+          }
+        }
+      }
+      if let Ok(base_classes) = self.cpp_data.base_class_names(&current_class) {
+        classes_to_visit.extend(base_classes);
+      }
+    }
+    result
+  }
+
+  /// For each abstract class `C` (one with pure virtual methods),
+  /// generates a concrete `<lib>_Overrider_<C>` subclass that stores a
+  /// `void* data` pointer plus one function pointer per overridable
+  /// virtual method, so that Rust code can implement the interface by
+  /// storing a boxed closure behind `data` and handing over a function
+  /// pointer that marshals through the FFI types. This generalizes the
+  /// callback pattern already used by `generate_slot_wrappers`: a
+  /// `set_<method>(func, data)` FFI method per virtual, plus
+  /// constructor/destructor and a `static_cast` up to `C*`.
+  fn generate_override_wrappers(&'a self) -> Result<Option<CppFfiHeaderData>> {
+    let include_file_name = "overrides";
+    let virtual_methods = self.virtual_methods_by_class();
+    let mut abstract_class_names: Vec<_> = virtual_methods
+      .keys()
+      .filter(|name| self.cpp_data.has_pure_virtual_methods(name))
+      .cloned()
+      .collect();
+    abstract_class_names.sort();
+    if abstract_class_names.is_empty() {
+      return Ok(None);
+    }
+    let void_ptr = CppType {
+      base: CppTypeBase::Void,
+      indirection: CppTypeIndirection::Ptr,
+      is_const: false,
+      is_const2: false,
+    };
+    let mut methods = Vec::new();
+    let mut overriders = Vec::new();
+    for class_name in abstract_class_names {
+      let overrider_class_name = format!("{}_Overrider_{}", self.cpp_ffi_lib_name, class_name);
+      let create_function = |kind: CppMethodKind,
+                             name: String,
+                             arguments: Vec<CppFunctionArgument>|
+       -> CppMethod {
+        CppMethod {
+          name: name,
+          class_membership: Some(CppMethodClassMembership {
+                                   class_type: CppTypeClassBase {
+                                     name: overrider_class_name.clone(),
+                                     template_arguments: None,
+                                   },
+                                   is_virtual: false,
+                                   is_pure_virtual: false,
+                                   is_const: false,
+                                   is_static: false,
+                                   visibility: CppVisibility::Public,
+                                   is_signal: false,
+                                   is_slot: false,
+                                   kind: kind,
+                                   fake: None,
+                                   field_accessor_type: None,
+                                 }),
+          operator: None,
+          return_type: CppType::void(),
+          arguments: arguments,
+          arguments_before_omitting: None,
+          allows_variadic_arguments: false,
+          include_file: include_file_name.to_string(),
+          origin_location: None,
+          template_arguments: None,
+          template_arguments_values: None,
+          declaration_code: None,
+          doc: None,
+          inheritance_chain: Vec::new(),
+          is_ffi_whitelisted: false,
+          is_unsafe_static_cast: false,
+          is_direct_static_cast: false,
+          is_fake_inherited_method: false,
+        }
+      };
+      methods.push(create_function(CppMethodKind::Constructor,
+                                   overrider_class_name.clone(),
+                                   vec![]));
+      methods.push(create_function(CppMethodKind::Destructor,
+                                   format!("~{}", overrider_class_name),
+                                   vec![]));
+      let mut overrides = Vec::new();
+      for method in self.all_overridable_virtual_methods(&virtual_methods, &class_name) {
+        let ffi_args = method
+          .arguments
+          .map_if_ok(|a| a.argument_type.to_cpp_ffi_type(CppTypeRole::NotReturnType))?;
+        let ffi_return_type = method
+          .return_type
+          .to_cpp_ffi_type(CppTypeRole::ReturnType)?;
+        let func_arguments = once(void_ptr.clone())
+          .chain(ffi_args.iter().map(|t| t.ffi_type.clone()))
+          .collect();
+        let function_type = CppFunctionPointerType {
+          return_type: Box::new(ffi_return_type.ffi_type.clone()),
+          arguments: func_arguments,
+          allows_variadic_arguments: false,
+        };
+        let set_args = vec![CppFunctionArgument {
+                               name: "func".to_string(),
+                               argument_type: CppType {
+                                 base: CppTypeBase::FunctionPointer(function_type.clone()),
+                                 indirection: CppTypeIndirection::None,
+                                 is_const: false,
+                                 is_const2: false,
+                               },
+                               has_default_value: false,
+                             },
+                             CppFunctionArgument {
+                               name: "data".to_string(),
+                               argument_type: void_ptr.clone(),
+                               has_default_value: false,
+                             }];
+        methods.push(create_function(CppMethodKind::Regular,
+                                     format!("set_{}", method.name),
+                                     set_args));
+        let membership = method
+          .class_membership
+          .as_ref()
+          .expect("virtual_methods_by_class only collects methods with a class membership");
+        overrides.push(CppOverriddenMethod {
+                          name: method.name.clone(),
+                          function_type: function_type,
+                          is_pure_virtual: membership.is_pure_virtual,
+                          is_const: membership.is_const,
+                          return_type: method.return_type.clone(),
+                          arguments: method.arguments.clone(),
+                        });
+      }
+      let cast_from = CppType {
+        base: CppTypeBase::Class(CppTypeClassBase {
+                                   name: overrider_class_name.clone(),
+                                   template_arguments: None,
+                                 }),
+        indirection: CppTypeIndirection::Ptr,
+        is_const: false,
+        is_const2: false,
+      };
+      let cast_to = CppType {
+        base: CppTypeBase::Class(CppTypeClassBase {
+                                   name: class_name.clone(),
+                                   template_arguments: None,
+                                 }),
+        indirection: CppTypeIndirection::Ptr,
+        is_const: false,
+        is_const2: false,
+      };
+      methods.push(create_cast_method("static_cast",
+                                      &cast_from,
+                                      &cast_to,
+                                      false,
+                                      true,
+                                      include_file_name));
+      overriders.push(CppFfiOverrider {
+                         overrider_class_name: overrider_class_name,
+                         base_class_name: class_name,
+                         overrides: overrides,
+                       });
+    }
+    Ok(Some(CppFfiHeaderData {
+              include_file_base_name: include_file_name.to_string(),
+              methods: self
+                .process_methods(include_file_name,
+                                 Some(CppTypeAllocationPlace::Heap),
+                                 methods.iter())?,
+              qt_slot_wrappers: Vec::new(),
+              protected_shims: Vec::new(),
+              overriders: overriders,
             }))
   }
 }